@@ -1,9 +1,16 @@
 use std::str::FromStr;
 use thiserror::Error;
+use unicode_segmentation::UnicodeSegmentation;
 
 const SUBJECT_MAX_LEN: usize = 50;
 const BODY_LINE_MAX_LEN: usize = 72;
 
+/// Counts `s` in grapheme clusters rather than bytes, so accented characters, CJK text,
+/// and emoji count as the user actually sees them.
+fn grapheme_len(s: &str) -> usize {
+    s.graphemes(true).count()
+}
+
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
 #[value(rename_all = "lowercase")]
 pub enum CommitType {
@@ -44,6 +51,103 @@ impl CommitType {
         ]
         .join(" ")
     }
+
+    /// Validates a raw type token against `config`'s allowed types (or the built-in list when
+    /// `config` is `None`/has no override), returning the token itself rather than a
+    /// [`CommitType`] - project configs may allow custom types this enum can't represent.
+    fn validate_token(raw: &str, config: Option<&Config>) -> Result<String, ValidationError> {
+        let raw = raw.trim();
+        let allowed = config.map_or_else(Self::allowed_list, Config::allowed_types);
+
+        if allowed.split_whitespace().any(|allowed_type| allowed_type == raw) {
+            Ok(raw.to_string())
+        } else {
+            Err(ValidationError::CommitTypeInvalid {
+                raw: raw.to_string(),
+                allowed,
+            })
+        }
+    }
+}
+
+/// How work-in-progress subjects (`WIP`, `wip:`) and well-formed autosquash subjects
+/// (`fixup! <target>`, `squash! <target>`, `amend! <target>`) are treated. A bare marker with
+/// no target is not governed by this policy; it is always rejected as a malformed subject.
+/// Local iteration wants these to pass; a commit meant to land
+/// on a protected branch usually wants them rejected - hence this is selectable rather than
+/// hardcoded either way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WipPolicy {
+    #[default]
+    Accept,
+    Warn,
+    Reject,
+}
+
+/// Project-level overrides for the rules this crate otherwise applies by default.
+///
+/// Load from a `.git-tools.toml` (or similar) with [`Config::load`]; any field left unset in
+/// the file falls back to the built-in default for that field.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Allowed commit type tokens. `None` falls back to [`CommitType::allowed_list`].
+    pub types: Option<Vec<String>>,
+    /// Overrides [`SUBJECT_MAX_LEN`]. `None` keeps the default.
+    pub subject_max_len: Option<usize>,
+    /// Overrides [`BODY_LINE_MAX_LEN`]. `None` keeps the default.
+    pub body_line_max_len: Option<usize>,
+    /// Whether a summary ending in a period is rejected.
+    pub require_no_trailing_period: bool,
+    /// Whether a summary not in the imperative mood is rejected.
+    pub require_imperative_mood: bool,
+    /// How WIP/incomplete-autosquash subjects are treated.
+    pub wip_policy: WipPolicy,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            types: None,
+            subject_max_len: None,
+            body_line_max_len: None,
+            require_no_trailing_period: true,
+            require_imperative_mood: true,
+            wip_policy: WipPolicy::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads a config from a TOML file, e.g. a repo's `.git-tools.toml`.
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path.as_ref())?;
+        Ok(toml::from_str(&text)?)
+    }
+
+    fn allowed_types(&self) -> String {
+        self.types
+            .as_ref()
+            .map_or_else(CommitType::allowed_list, |types| types.join(" "))
+    }
+
+    fn subject_max_len(&self) -> usize {
+        self.subject_max_len.unwrap_or(SUBJECT_MAX_LEN)
+    }
+
+    fn body_line_max_len(&self) -> usize {
+        self.body_line_max_len.unwrap_or(BODY_LINE_MAX_LEN)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("failed to read config file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to parse config file: {0}")]
+    Parse(#[from] toml::de::Error),
 }
 
 impl std::fmt::Display for CommitType {
@@ -76,7 +180,7 @@ impl FromStr for CommitType {
     }
 }
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum ValidationError {
     #[error("Commit type must be one of {allowed} - got '{raw}'")]
     CommitTypeInvalid { raw: String, allowed: String },
@@ -131,14 +235,179 @@ pub enum ValidationError {
     #[error("When a commit message has body/footers, it must include a blank line after the subject")]
     MessageMissingBlankLineAfterSubject,
 
-    #[error("BREAKING CHANGE footer must be the final non-comment line")]
-    MessageBreakingFooterNotLast,
-
     #[error("BREAKING CHANGE footer requires '!' in the subject")]
     MessageBreakingFooterMissingBang,
 
     #[error("Subject uses '!' but no BREAKING CHANGE footer was found")]
     MessageBangWithoutBreakingFooter,
+
+    #[error("Footer token '{token}' is malformed - expected 'Token: value' or 'Token #value'")]
+    MalformedFooterToken { token: String },
+
+    #[error("Summary should use the imperative mood - '{word}' looks like it isn't; try '{suggestion}'")]
+    SummaryNotImperative { word: String, suggestion: String },
+
+    #[error("Subject '{subject}' looks like a work-in-progress commit; squash or finish it before it lands")]
+    WorkInProgress { subject: String },
+}
+
+impl ValidationError {
+    /// A stable, machine-readable identifier for this rule, suitable for CI/editor output.
+    pub const fn rule_id(&self) -> &'static str {
+        match self {
+            ValidationError::CommitTypeInvalid { .. } => "commit-type-invalid",
+            ValidationError::SummaryEmpty => "summary-empty",
+            ValidationError::SummaryMultiline => "summary-multiline",
+            ValidationError::SummaryEndsWithPeriod => "summary-ends-with-period",
+            ValidationError::ScopeMultiline => "scope-multiline",
+            ValidationError::ScopeHasClosingParen => "scope-has-closing-paren",
+            ValidationError::ScopeEmpty => "scope-empty",
+            ValidationError::BodyEmpty => "body-empty",
+            ValidationError::BreakingNoteEmpty => "breaking-note-empty",
+            ValidationError::BreakingNoteMultiline => "breaking-note-multiline",
+            ValidationError::BodyLineTooLong { .. } => "body-line-too-long",
+            ValidationError::SubjectTooLong { .. } => "subject-too-long",
+            ValidationError::MessageSubjectMissing => "subject-missing",
+            ValidationError::MessageSubjectInvalidFormat { .. } => "subject-invalid-format",
+            ValidationError::MessageMissingBlankLineAfterSubject => {
+                "missing-blank-line-after-subject"
+            }
+            ValidationError::MessageBreakingFooterMissingBang => "breaking-footer-missing-bang",
+            ValidationError::MessageBangWithoutBreakingFooter => "bang-without-breaking-footer",
+            ValidationError::MalformedFooterToken { .. } => "malformed-footer-token",
+            ValidationError::SummaryNotImperative { .. } => "summary-not-imperative",
+            ValidationError::WorkInProgress { .. } => "work-in-progress",
+        }
+    }
+}
+
+/// Whether a [`Violation`] blocks the commit or is merely advisory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// A single validation failure, identified by a stable `rule` id for CI/editor consumption.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Violation {
+    pub rule: &'static str,
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub severity: Severity,
+}
+
+impl Violation {
+    fn at_line(error: ValidationError, line: usize) -> Self {
+        Violation {
+            line: Some(line),
+            ..Violation::from(error)
+        }
+    }
+
+    /// Downgrades this violation to [`Severity::Warning`], for rules a [`Config`] has chosen
+    /// to warn on rather than reject.
+    fn into_warning(mut self) -> Self {
+        self.severity = Severity::Warning;
+        self
+    }
+}
+
+impl From<ValidationError> for Violation {
+    fn from(error: ValidationError) -> Self {
+        Violation {
+            rule: error.rule_id(),
+            message: error.to_string(),
+            line: None,
+            column: None,
+            severity: Severity::Error,
+        }
+    }
+}
+
+/// All violations found in a commit message, gathered rather than short-circuited so CI
+/// and editor tooling can surface everything wrong with a message in one pass.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct Report {
+    pub violations: Vec<Violation>,
+}
+
+impl Report {
+    /// `true` if no violation has [`Severity::Error`] - warnings alone don't fail a report.
+    pub fn is_valid(&self) -> bool {
+        self.violations
+            .iter()
+            .all(|violation| violation.severity != Severity::Error)
+    }
+}
+
+/// Serializes a [`Report`] for consumption by CI or editor tooling.
+pub trait Emitter {
+    fn emit(&self, report: &Report) -> String;
+}
+
+/// Emits a [`Report`] as JSON.
+pub struct JsonEmitter;
+
+impl Emitter for JsonEmitter {
+    fn emit(&self, report: &Report) -> String {
+        serde_json::to_string_pretty(report).unwrap_or_default()
+    }
+}
+
+/// Emits a [`Report`] as Checkstyle-compatible XML, understood by most CI dashboards and editors.
+pub struct CheckstyleEmitter;
+
+impl Emitter for CheckstyleEmitter {
+    fn emit(&self, report: &Report) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<checkstyle version=\"4.3\">\n");
+        xml.push_str("  <file name=\"COMMIT_EDITMSG\">\n");
+        for violation in &report.violations {
+            let severity = match violation.severity {
+                Severity::Warning => "warning",
+                Severity::Error => "error",
+            };
+            xml.push_str(&format!(
+                "    <error line=\"{}\" column=\"{}\" severity=\"{severity}\" message=\"{}\" source=\"{}\"/>\n",
+                violation.line.unwrap_or(1),
+                violation.column.unwrap_or(1),
+                xml_escape(&violation.message),
+                violation.rule,
+            ));
+        }
+        xml.push_str("  </file>\n</checkstyle>\n");
+        xml
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A single Git trailer (footer) line, e.g. `Reviewed-by: Jane Doe` or `Closes #9`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Footer {
+    pub key: String,
+    pub value: String,
+}
+
+impl Footer {
+    fn is_breaking(&self) -> bool {
+        matches!(self.key.as_str(), "BREAKING CHANGE" | "BREAKING-CHANGE")
+    }
+}
+
+/// The body and footers recovered from a validated commit message.
+#[derive(Debug, Clone, Default)]
+pub struct ValidatedMessage {
+    pub body: Option<CommitBody>,
+    pub footers: Vec<Footer>,
 }
 
 #[derive(Debug, Clone)]
@@ -148,12 +417,12 @@ impl CommitSummary {
     pub fn as_str(&self) -> &str {
         &self.0
     }
-}
 
-impl FromStr for CommitSummary {
-    type Err = ValidationError;
-
-    fn from_str(raw_summary: &str) -> Result<Self, Self::Err> {
+    /// Builds a summary enforcing only structural validity (non-empty, single line). The
+    /// no-trailing-period and imperative-mood rules are applied separately by
+    /// [`parse_subject_line`] so a [`Config`] can toggle them - [`FromStr`] has no way to
+    /// accept a `Config`, so this is the only constructor that can skip them, and it's private.
+    fn from_str_unchecked(raw_summary: &str) -> Result<Self, ValidationError> {
         let trimmed = raw_summary.trim();
         if trimmed.is_empty() {
             return Err(ValidationError::SummaryEmpty);
@@ -164,14 +433,25 @@ impl FromStr for CommitSummary {
 
         let summary = trimmed.split_whitespace().collect::<Vec<_>>().join(" ");
 
-        if summary.ends_with('.') {
-            return Err(ValidationError::SummaryEndsWithPeriod);
-        }
-
         Ok(CommitSummary(summary))
     }
 }
 
+impl FromStr for CommitSummary {
+    type Err = ValidationError;
+
+    /// The public constructor always enforces the full default rule set (including the
+    /// no-trailing-period and imperative-mood checks), since this is the path used when
+    /// constructing a new message (e.g. [`CommitSubject::new`]) rather than validating an
+    /// existing one - there's no [`Config`] here to opt out of them.
+    fn from_str(raw_summary: &str) -> Result<Self, Self::Err> {
+        let summary = Self::from_str_unchecked(raw_summary)?;
+        check_no_trailing_period(&summary)?;
+        check_imperative_mood(&summary)?;
+        Ok(summary)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CommitScope(String);
 
@@ -220,15 +500,6 @@ impl FromStr for CommitBody {
             return Err(ValidationError::BodyEmpty);
         }
 
-        for line in body.lines() {
-            if !line.is_empty() && line.len() > BODY_LINE_MAX_LEN {
-                return Err(ValidationError::BodyLineTooLong {
-                    len: line.len(),
-                    line: line.to_string(),
-                });
-            }
-        }
-
         Ok(CommitBody(body.to_string()))
     }
 }
@@ -281,10 +552,10 @@ impl CommitSubject {
             format!("{}{}: {}", commit_type, bang, summary.as_str())
         };
 
-        let subject_length = subject.len();
+        let subject_length = grapheme_len(&subject);
         if subject_length > SUBJECT_MAX_LEN {
             let prefix_without_summary = subject.replace(summary.as_str(), "");
-            let budget = SUBJECT_MAX_LEN.saturating_sub(prefix_without_summary.len());
+            let budget = SUBJECT_MAX_LEN.saturating_sub(grapheme_len(&prefix_without_summary));
             return Err(ValidationError::SubjectTooLong {
                 len: subject_length,
                 budget,
@@ -320,7 +591,10 @@ pub fn new_commit_message(
     message_parts.join("\n")
 }
 
-pub fn validate_commit_message(raw_message: &str) -> Result<(), ValidationError> {
+pub fn validate_commit_message(
+    raw_message: &str,
+    config: Option<&Config>,
+) -> Result<ValidatedMessage, ValidationError> {
     let mut lines: Vec<&str> = raw_message
         .lines()
         .filter(|line| !line.starts_with('#'))
@@ -339,28 +613,32 @@ pub fn validate_commit_message(raw_message: &str) -> Result<(), ValidationError>
         .ok_or(ValidationError::MessageSubjectMissing)?
         .trim();
 
-    if is_autosquash_subject(subject_line) {
-        let rest = subject_line
-            .split_once(' ')
-            .map(|(_prefix, rest)| rest.trim())
-            .unwrap_or("");
-        if rest.is_empty() {
-            return Err(ValidationError::MessageSubjectInvalidFormat {
+    if is_targetless_autosquash_subject(subject_line) {
+        return Err(ValidationError::MessageSubjectInvalidFormat {
+            subject: subject_line.to_string(),
+        });
+    }
+
+    // `Warn` has no channel in this fail-fast API, so it's treated like `Accept`; use
+    // `validate_commit_message_report` to surface WIP subjects as non-blocking warnings.
+    if is_autosquash_subject(subject_line) || is_wip_subject(subject_line) {
+        return match config.map_or(WipPolicy::default(), |c| c.wip_policy) {
+            WipPolicy::Accept | WipPolicy::Warn => Ok(ValidatedMessage::default()),
+            WipPolicy::Reject => Err(ValidationError::WorkInProgress {
                 subject: subject_line.to_string(),
-            });
-        }
-        return Ok(());
+            }),
+        };
     }
 
-    let (commit_type, scope, summary, has_bang) = parse_subject_line(subject_line)?;
-    validate_subject_length(subject_line, summary.as_str())?;
+    let (_commit_type, scope, summary, has_bang) = parse_subject_line(subject_line, config)?;
+    validate_subject_length(subject_line, summary.as_str(), config)?;
 
     let rest = &lines[1..];
     if rest.is_empty() {
         if has_bang {
             return Err(ValidationError::MessageBangWithoutBreakingFooter);
         }
-        return Ok(());
+        return Ok(ValidatedMessage::default());
     }
 
     if !rest[0].trim().is_empty() {
@@ -372,10 +650,14 @@ pub fn validate_commit_message(raw_message: &str) -> Result<(), ValidationError>
         if has_bang {
             return Err(ValidationError::MessageBangWithoutBreakingFooter);
         }
-        return Ok(());
+        return Ok(ValidatedMessage::default());
     }
 
-    let (body_lines, breaking_note) = split_body_and_breaking_footer(content)?;
+    let (body_lines, footers) = parse_footers(content)?;
+    let breaking_note = footers
+        .iter()
+        .find(|footer| footer.is_breaking())
+        .map(|footer| footer.value.clone());
 
     if let Some(breaking_note) = breaking_note.as_ref() {
         if !has_bang {
@@ -386,30 +668,280 @@ pub fn validate_commit_message(raw_message: &str) -> Result<(), ValidationError>
         return Err(ValidationError::MessageBangWithoutBreakingFooter);
     }
 
-    if has_non_empty_body(body_lines) {
+    let body = if has_non_empty_body(body_lines) {
         let body_text = body_lines.join("\n");
-        let _ = CommitBody::from_str(&body_text)?;
+        check_body_line_lengths(&body_text, config)?;
+        Some(CommitBody::from_str(&body_text)?)
+    } else {
+        None
+    };
+
+    // Validate the scope by reconstructing it - this ensures scope parsing rules match the
+    // CLI rules (including ')' and newline checks).
+    if let Some(scope) = scope.as_ref() {
+        let _ = CommitScope::from_str(scope)?;
     }
 
-    // Validate subject type/scope/summary by reconstructing. This ensures scope parsing rules
-    // match the CLI rules (including ')' and newline checks).
-    let scope = scope.as_ref().map(|s| CommitScope::from_str(s)).transpose()?;
-    let breaking_note = breaking_note
-        .as_ref()
-        .map(|note| BreakingNote::from_str(note))
-        .transpose()?;
-    let _ = CommitSubject::new(commit_type, scope.as_ref(), &summary, breaking_note.as_ref())?;
+    Ok(ValidatedMessage { body, footers })
+}
 
-    Ok(())
+/// Like [`validate_commit_message`], but accumulates violations instead of returning on the
+/// first one - the shape CI and editor integrations need to report everything at once.
+/// Checks that depend on an earlier step still stop at that step's first failure: the subject
+/// line yields at most one of its format, type, summary and length violations, a malformed
+/// footer token skips the breaking-footer and body checks, and a WIP or autosquash subject is
+/// reported on its own.
+pub fn validate_commit_message_report(raw_message: &str, config: Option<&Config>) -> Report {
+    let mut violations = Vec::new();
+
+    // Tracks each surviving line's 1-based line number in `raw_message`, so violations can
+    // point an editor/CI at the right spot even after `#`-comment and blank-line stripping.
+    let mut lines: Vec<(usize, &str)> = raw_message
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line))
+        .filter(|(_, line)| !line.starts_with('#'))
+        .collect();
+
+    while matches!(lines.first(), Some((_, line)) if line.trim().is_empty()) {
+        lines.remove(0);
+    }
+    while matches!(lines.last(), Some((_, line)) if line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let Some(&(subject_line_no, subject_line)) = lines.first() else {
+        violations.push(ValidationError::MessageSubjectMissing.into());
+        return Report { violations };
+    };
+    let subject_line = subject_line.trim();
+
+    if is_targetless_autosquash_subject(subject_line) {
+        violations.push(Violation::at_line(
+            ValidationError::MessageSubjectInvalidFormat {
+                subject: subject_line.to_string(),
+            },
+            subject_line_no,
+        ));
+        return Report { violations };
+    }
+
+    if is_autosquash_subject(subject_line) || is_wip_subject(subject_line) {
+        let error = ValidationError::WorkInProgress {
+            subject: subject_line.to_string(),
+        };
+        match config.map_or(WipPolicy::default(), |c| c.wip_policy) {
+            WipPolicy::Accept => {}
+            WipPolicy::Warn => {
+                violations.push(Violation::at_line(error, subject_line_no).into_warning());
+            }
+            WipPolicy::Reject => violations.push(Violation::at_line(error, subject_line_no)),
+        }
+        return Report { violations };
+    }
+
+    let parsed_subject = parse_subject_line(subject_line, config);
+    if let Err(err) = parsed_subject.clone() {
+        violations.push(Violation::at_line(err, subject_line_no));
+    } else if let Ok((_, _, summary, _)) = &parsed_subject {
+        if let Err(err) = validate_subject_length(subject_line, summary.as_str(), config) {
+            violations.push(Violation::at_line(err, subject_line_no));
+        }
+    }
+    // Read the bang off the raw prefix rather than the parse result, so a type or summary
+    // failure in `parse_subject_line` doesn't hide it from the footer checks below.
+    let has_bang = subject_line
+        .split_once(": ")
+        .is_some_and(|(prefix, _)| prefix.trim().ends_with('!'));
+
+    let rest = &lines[1..];
+    if rest.is_empty() {
+        if has_bang {
+            violations.push(Violation::at_line(
+                ValidationError::MessageBangWithoutBreakingFooter,
+                subject_line_no,
+            ));
+        }
+        return Report { violations };
+    }
+
+    let (blank_line_no, blank_line) = rest[0];
+    if !blank_line.trim().is_empty() {
+        violations.push(Violation::at_line(
+            ValidationError::MessageMissingBlankLineAfterSubject,
+            blank_line_no,
+        ));
+    }
+
+    let content = &rest[1..];
+    if content.is_empty() {
+        if has_bang {
+            violations.push(Violation::at_line(
+                ValidationError::MessageBangWithoutBreakingFooter,
+                subject_line_no,
+            ));
+        }
+        return Report { violations };
+    }
+
+    match parse_footers_numbered(content) {
+        Err((err, line_no)) => violations.push(Violation::at_line(err, line_no)),
+        Ok((body_lines, footers)) => {
+            let breaking = footers.iter().find(|(_, footer)| footer.is_breaking());
+
+            if let Some(&(line_no, ref footer)) = breaking {
+                if !has_bang {
+                    violations.push(Violation::at_line(
+                        ValidationError::MessageBreakingFooterMissingBang,
+                        line_no,
+                    ));
+                }
+                if let Err(err) = BreakingNote::from_str(&footer.value) {
+                    violations.push(Violation::at_line(err, line_no));
+                }
+            } else if has_bang {
+                violations.push(Violation::at_line(
+                    ValidationError::MessageBangWithoutBreakingFooter,
+                    subject_line_no,
+                ));
+            }
+
+            let body_text_lines: Vec<&str> = body_lines.iter().map(|&(_, line)| line).collect();
+            if has_non_empty_body(&body_text_lines) {
+                let body_text = body_text_lines.join("\n");
+                if let Err((err, line_no)) = check_body_line_lengths_numbered(body_lines, config) {
+                    violations.push(Violation::at_line(err, line_no));
+                }
+                if let Err(err) = CommitBody::from_str(&body_text) {
+                    let line_no = body_lines
+                        .first()
+                        .map_or(blank_line_no + 1, |&(line_no, _)| line_no);
+                    violations.push(Violation::at_line(err, line_no));
+                }
+            }
+        }
+    }
+
+    if let Ok((_, Some(scope), _, _)) = &parsed_subject {
+        if let Err(err) = CommitScope::from_str(scope) {
+            violations.push(Violation::at_line(err, subject_line_no));
+        }
+    }
+
+    Report { violations }
+}
+
+/// The semantic-version bump implied by a commit, from least to most severe so the highest
+/// variant across a set of commits can be found with [`Iterator::max`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionIncrement {
+    None,
+    Patch,
+    Minor,
+    Major,
+}
+
+impl VersionIncrement {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            VersionIncrement::Major => "major",
+            VersionIncrement::Minor => "minor",
+            VersionIncrement::Patch => "patch",
+            VersionIncrement::None => "none",
+        }
+    }
+}
+
+impl std::fmt::Display for VersionIncrement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Derives the semantic-version bump implied by a set of commit messages, reusing the same
+/// subject/footer parsing as [`validate_commit_message`] (and the same `config`, so a custom
+/// type list is honored rather than rejecting every commit of a renamed type): a `!` bang or
+/// `BREAKING CHANGE` footer on any commit forces [`VersionIncrement::Major`], otherwise any
+/// `feat` commit forces [`VersionIncrement::Minor`], otherwise any `fix`/`perf` commit forces
+/// [`VersionIncrement::Patch`]; messages that don't parse as conventional commits are ignored.
+pub fn version_increment(messages: &[&str], config: Option<&Config>) -> VersionIncrement {
+    messages
+        .iter()
+        .map(|message| single_message_increment(message, config))
+        .max()
+        .unwrap_or(VersionIncrement::None)
+}
+
+fn single_message_increment(raw_message: &str, config: Option<&Config>) -> VersionIncrement {
+    let mut lines: Vec<&str> = raw_message
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect();
+
+    while matches!(lines.first(), Some(line) if line.trim().is_empty()) {
+        lines.remove(0);
+    }
+    while matches!(lines.last(), Some(line) if line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let Some(subject_line) = lines.first().copied().map(str::trim) else {
+        return VersionIncrement::None;
+    };
+
+    if is_targetless_autosquash_subject(subject_line)
+        || is_autosquash_subject(subject_line)
+        || is_wip_subject(subject_line)
+    {
+        return VersionIncrement::None;
+    }
+
+    let Ok((commit_type, _scope, _summary, has_bang)) = parse_subject_line(subject_line, config)
+    else {
+        return VersionIncrement::None;
+    };
+
+    if has_bang {
+        return VersionIncrement::Major;
+    }
+
+    let content = lines.get(2..).unwrap_or(&[]);
+    if let Ok((_body_lines, footers)) = parse_footers(content) {
+        if footers.iter().any(Footer::is_breaking) {
+            return VersionIncrement::Major;
+        }
+    }
+
+    match commit_type.as_str() {
+        "feat" => VersionIncrement::Minor,
+        "fix" | "perf" => VersionIncrement::Patch,
+        _ => VersionIncrement::None,
+    }
 }
 
 fn is_autosquash_subject(subject: &str) -> bool {
     subject.starts_with("fixup! ") || subject.starts_with("squash! ") || subject.starts_with("amend! ")
 }
 
+/// A subject beginning with a bare `WIP`/`wip:` marker.
+fn is_wip_subject(subject: &str) -> bool {
+    subject == "WIP" || subject.starts_with("WIP ") || subject.starts_with("wip:")
+}
+
+/// A bare `fixup!`/`squash!`/`amend!` marker with no target summary after it. This is a
+/// malformed subject rather than a WIP/fixup *choice*, so it's rejected regardless of
+/// `WipPolicy` - unlike a genuine `WIP`/`wip:` subject or a well-formed `marker! <target>`.
+fn is_targetless_autosquash_subject(subject: &str) -> bool {
+    ["fixup!", "squash!", "amend!"]
+        .iter()
+        .any(|marker| subject.strip_prefix(marker).is_some_and(|rest| rest.trim().is_empty()))
+}
+
 fn parse_subject_line(
     subject: &str,
-) -> Result<(CommitType, Option<String>, CommitSummary, bool), ValidationError> {
+    config: Option<&Config>,
+) -> Result<(String, Option<String>, CommitSummary, bool), ValidationError> {
     let (type_scope_bang, summary_raw) = subject
         .split_once(": ")
         .ok_or_else(|| ValidationError::MessageSubjectInvalidFormat {
@@ -444,26 +976,95 @@ fn parse_subject_line(
                 subject: subject.to_string(),
             });
         }
-        let commit_type = <CommitType as FromStr>::from_str(raw_type)?;
+        let commit_type = CommitType::validate_token(raw_type, config)?;
         let scope = raw_scope.to_string();
         (commit_type, Some(scope))
     } else {
-        let commit_type = <CommitType as FromStr>::from_str(prefix)?;
+        let commit_type = CommitType::validate_token(prefix, config)?;
         (commit_type, None)
     };
 
-    let summary = CommitSummary::from_str(summary_raw)?;
+    let summary = CommitSummary::from_str_unchecked(summary_raw)?;
+    if config.is_none_or(|c| c.require_no_trailing_period) {
+        check_no_trailing_period(&summary)?;
+    }
+    if config.is_none_or(|c| c.require_imperative_mood) {
+        check_imperative_mood(&summary)?;
+    }
     Ok((commit_type, scope, summary, has_bang))
 }
 
-fn validate_subject_length(subject: &str, summary: &str) -> Result<(), ValidationError> {
-    let len = subject.len();
-    if len <= SUBJECT_MAX_LEN {
+fn check_no_trailing_period(summary: &CommitSummary) -> Result<(), ValidationError> {
+    if summary.as_str().ends_with('.') {
+        return Err(ValidationError::SummaryEndsWithPeriod);
+    }
+    Ok(())
+}
+
+/// Common third-person verbs seen in non-imperative summaries. Past-tense and gerund forms
+/// are caught by the generic `-ed`/`-ing` suffix rules below rather than listed here.
+const NON_IMPERATIVE_VERBS: &[&str] = &[
+    "fixes", "adds", "removes", "updates", "changes", "creates", "deletes", "refactors",
+    "improves",
+];
+
+/// Imperative verbs that happen to end in "ed"/"ing" in their base form, so the suffix
+/// heuristic below would otherwise mistake them for past tense or gerunds, e.g. "Embed the
+/// polyfill" or "Bring in the new client".
+const IMPERATIVE_VERBS_ENDING_IN_ED_OR_ING: &[&str] = &[
+    "embed", "exceed", "succeed", "proceed", "seed", "bring", "string", "spring",
+];
+
+fn check_imperative_mood(summary: &CommitSummary) -> Result<(), ValidationError> {
+    let Some(first_word) = summary.as_str().split_whitespace().next() else {
+        return Ok(());
+    };
+    let lower = first_word.to_lowercase();
+
+    if IMPERATIVE_VERBS_ENDING_IN_ED_OR_ING.contains(&lower.as_str()) {
+        return Ok(());
+    }
+
+    let looks_non_imperative = lower.ends_with("ed")
+        || lower.ends_with("ing")
+        || NON_IMPERATIVE_VERBS.contains(&lower.as_str());
+
+    if !looks_non_imperative {
+        return Ok(());
+    }
+
+    Err(ValidationError::SummaryNotImperative {
+        word: first_word.to_string(),
+        suggestion: imperative_stem(&lower),
+    })
+}
+
+/// Heuristically strips a third-person/past-tense/gerund suffix to suggest the imperative
+/// stem, e.g. "fixes" -> "fix", "added" -> "add", "fixing" -> "fix".
+fn imperative_stem(word: &str) -> String {
+    for suffix in ["ing", "ed", "es", "s"] {
+        if let Some(root) = word.strip_suffix(suffix) {
+            if !root.is_empty() {
+                return root.to_string();
+            }
+        }
+    }
+    word.to_string()
+}
+
+fn validate_subject_length(
+    subject: &str,
+    summary: &str,
+    config: Option<&Config>,
+) -> Result<(), ValidationError> {
+    let max_len = config.map_or(SUBJECT_MAX_LEN, Config::subject_max_len);
+    let len = grapheme_len(subject);
+    if len <= max_len {
         return Ok(());
     }
 
     let prefix_without_summary = subject.replace(summary, "");
-    let budget = SUBJECT_MAX_LEN.saturating_sub(prefix_without_summary.len());
+    let budget = max_len.saturating_sub(grapheme_len(&prefix_without_summary));
     Err(ValidationError::SubjectTooLong {
         len,
         budget,
@@ -471,50 +1072,168 @@ fn validate_subject_length(subject: &str, summary: &str) -> Result<(), Validatio
     })
 }
 
-fn split_body_and_breaking_footer<'a>(
+fn check_body_line_lengths(body: &str, config: Option<&Config>) -> Result<(), ValidationError> {
+    let max_len = config.map_or(BODY_LINE_MAX_LEN, Config::body_line_max_len);
+    for line in body.lines() {
+        let len = grapheme_len(line);
+        if !line.is_empty() && len > max_len {
+            return Err(ValidationError::BodyLineTooLong {
+                len,
+                line: line.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Splits `content` into the free-form body and the trailing footer block, per the
+/// Conventional Commits footer grammar: the footer block is the contiguous run of lines
+/// after the last blank line, provided the first of those lines itself starts a footer.
+/// Once a footer block is found, every subsequent non-blank line either starts a new
+/// footer or continues the value of the previous one.
+fn parse_footers<'a>(
     content: &'a [&'a str],
-) -> Result<(&'a [&'a str], Option<String>), ValidationError> {
-    let breaking_indices: Vec<usize> = content
+) -> Result<(&'a [&'a str], Vec<Footer>), ValidationError> {
+    let block_start = content
         .iter()
-        .enumerate()
-        .filter_map(|(idx, line)| {
-            if line.trim_start().starts_with("BREAKING CHANGE:") {
-                Some(idx)
-            } else {
-                None
-            }
-        })
-        .collect();
+        .rposition(|line| line.trim().is_empty())
+        .map_or(0, |idx| idx + 1);
 
-    if breaking_indices.is_empty() {
-        return Ok((content, None));
+    let block = &content[block_start..];
+    let Some(first) = block.first() else {
+        return Ok((content, Vec::new()));
+    };
+    if parse_footer_line(first).is_none() {
+        return Ok((content, Vec::new()));
     }
 
-    if breaking_indices.len() != 1 || breaking_indices[0] != content.len() - 1 {
-        return Err(ValidationError::MessageBreakingFooterNotLast);
+    let mut footers: Vec<Footer> = Vec::new();
+    for line in block {
+        if let Some((key, value)) = parse_footer_line(line) {
+            if value.is_empty() {
+                return Err(ValidationError::MalformedFooterToken { token: key });
+            }
+            footers.push(Footer { key, value });
+        } else {
+            let footer = footers
+                .last_mut()
+                .expect("footer block always starts with a footer line");
+            footer.value.push(' ');
+            footer.value.push_str(line.trim());
+        }
     }
 
-    let line = content.last().copied().unwrap_or("").trim_end();
-    let note_raw = line
-        .trim_start()
-        .strip_prefix("BREAKING CHANGE:")
-        .unwrap_or("");
-    let note = note_raw.strip_prefix(' ').unwrap_or(note_raw).trim();
-    if note.is_empty() {
-        return Err(ValidationError::BreakingNoteEmpty);
+    let body_lines = if block_start == 0 {
+        &[]
+    } else {
+        &content[..block_start - 1]
+    };
+
+    Ok((body_lines, footers))
+}
+
+/// Like [`parse_footers`], but carries each surviving line's original 1-based line number
+/// through the split, and reports the line a malformed footer token was found on, so
+/// [`validate_commit_message_report`] can point violations at the right spot in the source
+/// message instead of the post-filter content.
+#[allow(clippy::type_complexity)]
+fn parse_footers_numbered<'a>(
+    content: &'a [(usize, &'a str)],
+) -> Result<(&'a [(usize, &'a str)], Vec<(usize, Footer)>), (ValidationError, usize)> {
+    let block_start = content
+        .iter()
+        .rposition(|(_, line)| line.trim().is_empty())
+        .map_or(0, |idx| idx + 1);
+
+    let block = &content[block_start..];
+    let Some(&(_, first)) = block.first() else {
+        return Ok((content, Vec::new()));
+    };
+    if parse_footer_line(first).is_none() {
+        return Ok((content, Vec::new()));
     }
 
-    if content.len() == 1 {
-        return Ok((&[], Some(note.to_string())));
+    let mut footers: Vec<(usize, Footer)> = Vec::new();
+    for &(line_no, line) in block {
+        if let Some((key, value)) = parse_footer_line(line) {
+            if value.is_empty() {
+                return Err((ValidationError::MalformedFooterToken { token: key }, line_no));
+            }
+            footers.push((line_no, Footer { key, value }));
+        } else {
+            let (_, footer) = footers
+                .last_mut()
+                .expect("footer block always starts with a footer line");
+            footer.value.push(' ');
+            footer.value.push_str(line.trim());
+        }
     }
 
-    let before = content[content.len() - 2];
-    if !before.trim().is_empty() {
-        return Err(ValidationError::MessageBreakingFooterNotLast);
+    let body_lines = if block_start == 0 {
+        &[]
+    } else {
+        &content[..block_start - 1]
+    };
+
+    Ok((body_lines, footers))
+}
+
+/// Recognizes a single footer line, returning its `(key, value)` pair.
+///
+/// A footer starts with a token containing no whitespace (hyphens stand in for spaces),
+/// followed by `": "` or `" #"` and a value - e.g. `Reviewed-by: Jane Doe` or `Closes #9`.
+/// `BREAKING CHANGE`/`BREAKING-CHANGE` is the one token allowed to contain a space.
+fn parse_footer_line(line: &str) -> Option<(String, String)> {
+    if let Some(value) = line.strip_prefix("BREAKING CHANGE:") {
+        return Some(("BREAKING CHANGE".to_string(), value.trim().to_string()));
+    }
+    if let Some(value) = line.strip_prefix("BREAKING-CHANGE:") {
+        return Some(("BREAKING-CHANGE".to_string(), value.trim().to_string()));
     }
 
-    let body_lines = &content[..content.len() - 2];
-    Ok((body_lines, Some(note.to_string())))
+    let colon_at = line.find(": ");
+    let hash_at = line.find(" #");
+    let sep = match (colon_at, hash_at) {
+        (Some(colon), Some(hash)) => colon.min(hash),
+        (Some(colon), None) => colon,
+        (None, Some(hash)) => hash,
+        (None, None) => return None,
+    };
+
+    let key = &line[..sep];
+    if key.is_empty() || key.chars().any(char::is_whitespace) {
+        return None;
+    }
+
+    let value = if hash_at == Some(sep) {
+        line[sep + 1..].trim()
+    } else {
+        line[sep + 2..].trim()
+    };
+
+    Some((key.to_string(), value.to_string()))
+}
+
+/// Like [`check_body_line_lengths`], but checks `(line_no, line)` pairs and returns the
+/// offending line's original line number alongside the error.
+fn check_body_line_lengths_numbered(
+    body_lines: &[(usize, &str)],
+    config: Option<&Config>,
+) -> Result<(), (ValidationError, usize)> {
+    let max_len = config.map_or(BODY_LINE_MAX_LEN, Config::body_line_max_len);
+    for &(line_no, line) in body_lines {
+        let len = grapheme_len(line);
+        if !line.is_empty() && len > max_len {
+            return Err((
+                ValidationError::BodyLineTooLong {
+                    len,
+                    line: line.to_string(),
+                },
+                line_no,
+            ));
+        }
+    }
+    Ok(())
 }
 
 fn has_non_empty_body(body_lines: &[&str]) -> bool {
@@ -531,14 +1250,29 @@ mod tests {
         assert!(matches!(err, ValidationError::SummaryEndsWithPeriod));
     }
 
+    #[test]
+    fn commit_summary_from_str_rejects_non_imperative_mood() {
+        let err = CommitSummary::from_str("Fixes the bug").unwrap_err();
+        assert!(matches!(err, ValidationError::SummaryNotImperative { .. }));
+    }
+
+    #[test]
+    fn commit_subject_new_cannot_bypass_summary_checks() {
+        // CommitSummary has no public constructor other than the checked `FromStr` impl, so a
+        // `CommitSubject` built from one is guaranteed to carry the no-trailing-period and
+        // imperative-mood invariants - there's no way to end up with "fix: Fixed the bug."
+        CommitSummary::from_str("Fixed the bug.").unwrap_err();
+        CommitSummary::from_str("Fixes the bug").unwrap_err();
+    }
+
     #[test]
     fn validate_allows_subject_only() {
-        validate_commit_message("fix: Handle empty input\n").unwrap();
+        validate_commit_message("fix: Handle empty input\n", None).unwrap();
     }
 
     #[test]
     fn validate_rejects_body_without_blank_line() {
-        let err = validate_commit_message("fix: Handle empty input\nBody\n").unwrap_err();
+        let err = validate_commit_message("fix: Handle empty input\nBody\n", None).unwrap_err();
         assert!(matches!(
             err,
             ValidationError::MessageMissingBlankLineAfterSubject
@@ -548,13 +1282,19 @@ mod tests {
     #[test]
     fn validate_allows_breaking_change_footer_with_bang() {
         let msg = "feat!: Change API\n\nBREAKING CHANGE: Old thing removed\n";
-        validate_commit_message(msg).unwrap();
+        validate_commit_message(msg, None).unwrap();
+    }
+
+    #[test]
+    fn validate_allows_breaking_change_footer_wrapped_onto_a_continuation_line() {
+        let msg = "feat!: Change API\n\nBREAKING CHANGE: Old thing removed because it was\ninsecure and broken.\n";
+        validate_commit_message(msg, None).unwrap();
     }
 
     #[test]
     fn validate_rejects_breaking_change_footer_without_bang() {
         let msg = "feat: Change API\n\nBREAKING CHANGE: Old thing removed\n";
-        let err = validate_commit_message(msg).unwrap_err();
+        let err = validate_commit_message(msg, None).unwrap_err();
         assert!(matches!(
             err,
             ValidationError::MessageBreakingFooterMissingBang
@@ -563,6 +1303,444 @@ mod tests {
 
     #[test]
     fn validate_allows_fixup_commits() {
-        validate_commit_message("fixup! feat: Add feature\n").unwrap();
+        validate_commit_message("fixup! feat: Add feature\n", None).unwrap();
+    }
+
+    #[test]
+    fn validate_collects_multiple_footers() {
+        let msg = "fix: Handle empty input\n\nReviewed-by: Jane Doe\nRefs: #123\nCloses #9\n";
+        let parsed = validate_commit_message(msg, None).unwrap();
+        assert_eq!(
+            parsed.footers,
+            vec![
+                Footer {
+                    key: "Reviewed-by".to_string(),
+                    value: "Jane Doe".to_string(),
+                },
+                Footer {
+                    key: "Refs".to_string(),
+                    value: "#123".to_string(),
+                },
+                Footer {
+                    key: "Closes".to_string(),
+                    value: "#9".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_treats_breaking_change_hyphenated_as_equivalent() {
+        let msg = "feat!: Change API\n\nBREAKING-CHANGE: Old thing removed\n";
+        let parsed = validate_commit_message(msg, None).unwrap();
+        assert_eq!(parsed.footers[0].key, "BREAKING-CHANGE");
+    }
+
+    #[test]
+    fn validate_allows_body_and_footers_together() {
+        let msg =
+            "fix: Handle empty input\n\nExplain the fix in detail.\n\nReviewed-by: Jane Doe\n";
+        let parsed = validate_commit_message(msg, None).unwrap();
+        assert_eq!(parsed.body.unwrap().as_str(), "Explain the fix in detail.");
+        assert_eq!(parsed.footers[0].key, "Reviewed-by");
+    }
+
+    #[test]
+    fn validate_rejects_malformed_footer_token() {
+        let msg = "fix: Handle empty input\n\nReviewed-by: \n";
+        let err = validate_commit_message(msg, None).unwrap_err();
+        assert!(matches!(err, ValidationError::MalformedFooterToken { .. }));
+    }
+
+    #[test]
+    fn subject_length_counts_graphemes_not_bytes() {
+        // 40 emoji graphemes: ~160 bytes, but the subject is only 45 graphemes long.
+        let summary = CommitSummary::from_str(&"🎉".repeat(40)).unwrap();
+        let subject = CommitSubject::new(CommitType::Fix, None, &summary, None).unwrap();
+        assert!(subject.as_str().len() > SUBJECT_MAX_LEN);
+        assert!(grapheme_len(subject.as_str()) <= SUBJECT_MAX_LEN);
+    }
+
+    #[test]
+    fn body_line_length_counts_graphemes_not_bytes() {
+        let line = "é".repeat(80);
+        assert!(line.len() > BODY_LINE_MAX_LEN);
+        assert_eq!(grapheme_len(&line), 80);
+        check_body_line_lengths(&line, None).unwrap_err();
+
+        let short_line = "é".repeat(BODY_LINE_MAX_LEN);
+        assert!(short_line.len() > BODY_LINE_MAX_LEN);
+        check_body_line_lengths(&short_line, None).unwrap();
+    }
+
+    #[test]
+    fn validate_rejects_non_imperative_summary() {
+        for subject in ["fix: Fixes the bug", "feat: Added a feature", "fix: Fixing crash"] {
+            let err = validate_commit_message(&format!("{subject}\n"), None).unwrap_err();
+            assert!(matches!(err, ValidationError::SummaryNotImperative { .. }));
+        }
+    }
+
+    #[test]
+    fn validate_allows_imperative_summary() {
+        validate_commit_message("fix: Fix the bug\n", None).unwrap();
+    }
+
+    #[test]
+    fn validate_allows_imperative_verbs_that_end_in_ed_or_ing() {
+        for subject in [
+            "feat: Embed the polyfill",
+            "feat: Exceed the previous limit",
+            "fix: Succeed on the first retry",
+            "feat: Proceed past the checkpoint",
+            "fix: Seed the test database",
+            "feat: Bring in the new client",
+            "feat: String the beads together",
+            "feat: Spring the trap early",
+        ] {
+            validate_commit_message(&format!("{subject}\n"), None).unwrap();
+        }
+    }
+
+    #[test]
+    fn imperative_stem_suggests_base_verb() {
+        assert_eq!(imperative_stem("fixes"), "fix");
+        assert_eq!(imperative_stem("added"), "add");
+        assert_eq!(imperative_stem("fixing"), "fix");
+    }
+
+    #[test]
+    fn report_accumulates_multiple_violations() {
+        let msg = "fix: Fixes the bug\nBody\n";
+        let report = validate_commit_message_report(msg, None);
+        assert!(!report.is_valid());
+        let rules: Vec<&str> = report.violations.iter().map(|v| v.rule).collect();
+        assert!(rules.contains(&"summary-not-imperative"));
+        assert!(rules.contains(&"missing-blank-line-after-subject"));
+    }
+
+    #[test]
+    fn report_violations_point_at_the_original_line_not_the_post_filter_line() {
+        // Two leading `#` instructional comments and a blank line push the real subject and
+        // body down to lines 4 and 5 of the raw COMMIT_EDITMSG.
+        let msg = "# comment one\n# comment two\n\nfix: Fixes the bug\nBody\n";
+        let report = validate_commit_message_report(msg, None);
+
+        let imperative = report
+            .violations
+            .iter()
+            .find(|v| v.rule == "summary-not-imperative")
+            .expect("summary-not-imperative violation");
+        assert_eq!(imperative.line, Some(4));
+
+        let missing_blank_line = report
+            .violations
+            .iter()
+            .find(|v| v.rule == "missing-blank-line-after-subject")
+            .expect("missing-blank-line-after-subject violation");
+        assert_eq!(missing_blank_line.line, Some(5));
+    }
+
+    #[test]
+    fn report_body_line_too_long_points_at_the_original_line() {
+        let long_line = "x".repeat(SUBJECT_MAX_LEN.max(BODY_LINE_MAX_LEN) + 1);
+        let msg = format!("# comment\n\nfix: Fix the bug\n\n{long_line}\n");
+        let report = validate_commit_message_report(&msg, None);
+
+        let violation = report
+            .violations
+            .iter()
+            .find(|v| v.rule == "body-line-too-long")
+            .expect("body-line-too-long violation");
+        assert_eq!(violation.line, Some(5));
+    }
+
+    #[test]
+    fn report_breaking_footer_missing_bang_points_at_the_footer_line() {
+        let msg = "# comment\n\nfeat: Something\n\nBREAKING CHANGE: oops\n";
+        let report = validate_commit_message_report(msg, None);
+
+        let violation = report
+            .violations
+            .iter()
+            .find(|v| v.rule == "breaking-footer-missing-bang")
+            .expect("breaking-footer-missing-bang violation");
+        assert_eq!(violation.line, Some(5));
+    }
+
+    #[test]
+    fn report_scope_violation_points_at_the_subject_line() {
+        let msg = "# comment\n\nfix(a)b): Send update\n\nBody text\n";
+        let report = validate_commit_message_report(msg, None);
+
+        let violation = report
+            .violations
+            .iter()
+            .find(|v| v.rule == "scope-has-closing-paren")
+            .expect("scope-has-closing-paren violation");
+        assert_eq!(violation.line, Some(3));
+    }
+
+    #[test]
+    fn report_is_valid_for_a_clean_message() {
+        let report = validate_commit_message_report("fix: Handle empty input\n", None);
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn json_emitter_serializes_violations() {
+        let report = validate_commit_message_report("fix: Fixes the bug\n", None);
+        let json = JsonEmitter.emit(&report);
+        assert!(json.contains("\"rule\""));
+        assert!(json.contains("summary-not-imperative"));
+    }
+
+    #[test]
+    fn checkstyle_emitter_wraps_violations_in_xml() {
+        let report = validate_commit_message_report("fix: Fixes the bug\n", None);
+        let xml = CheckstyleEmitter.emit(&report);
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("<checkstyle"));
+        assert!(xml.contains("source=\"summary-not-imperative\""));
+    }
+
+    #[test]
+    fn config_allows_custom_commit_types() {
+        let config = Config {
+            types: Some(vec!["security".to_string()]),
+            ..Config::default()
+        };
+        validate_commit_message("security: Patch a vulnerability\n", Some(&config)).unwrap();
+        let err = validate_commit_message("fix: Handle empty input\n", Some(&config)).unwrap_err();
+        assert!(matches!(err, ValidationError::CommitTypeInvalid { .. }));
+    }
+
+    #[test]
+    fn config_overrides_length_limits() {
+        let config = Config {
+            subject_max_len: Some(10),
+            ..Config::default()
+        };
+        let err = validate_commit_message("fix: Handle empty input\n", Some(&config)).unwrap_err();
+        assert!(matches!(err, ValidationError::SubjectTooLong { .. }));
+    }
+
+    #[test]
+    fn config_can_disable_imperative_mood_and_period_rules() {
+        let config = Config {
+            require_no_trailing_period: false,
+            require_imperative_mood: false,
+            ..Config::default()
+        };
+        validate_commit_message("fix: Fixes the bug.\n", Some(&config)).unwrap();
+    }
+
+    #[test]
+    fn config_parses_from_a_git_tools_toml_snippet() {
+        let toml = r#"
+            types = ["security", "fix", "feat"]
+            subject_max_len = 60
+            body_line_max_len = 80
+            require_no_trailing_period = false
+            require_imperative_mood = false
+            wip_policy = "reject"
+        "#;
+
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.types,
+            Some(vec![
+                "security".to_string(),
+                "fix".to_string(),
+                "feat".to_string(),
+            ])
+        );
+        assert_eq!(config.subject_max_len, Some(60));
+        assert_eq!(config.body_line_max_len, Some(80));
+        assert!(!config.require_no_trailing_period);
+        assert!(!config.require_imperative_mood);
+        assert_eq!(config.wip_policy, WipPolicy::Reject);
+    }
+
+    #[test]
+    fn config_toml_fields_fall_back_to_defaults_when_absent() {
+        let config: Config = toml::from_str("types = [\"security\"]").unwrap();
+        assert_eq!(config.types, Some(vec!["security".to_string()]));
+        assert_eq!(config.subject_max_len, None);
+        assert_eq!(config.body_line_max_len, None);
+        assert!(config.require_no_trailing_period);
+        assert!(config.require_imperative_mood);
+        assert_eq!(config.wip_policy, WipPolicy::Accept);
+    }
+
+    #[test]
+    fn config_load_reads_and_parses_a_git_tools_toml_file() {
+        let mut path = std::env::temp_dir();
+        path.push(format!(
+            "git-tools-config-load-test-{:?}.toml",
+            std::thread::current().id()
+        ));
+        std::fs::write(&path, "types = [\"security\"]\nwip_policy = \"warn\"\n").unwrap();
+
+        let config = Config::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(config.types, Some(vec!["security".to_string()]));
+        assert_eq!(config.wip_policy, WipPolicy::Warn);
+    }
+
+    #[test]
+    fn config_load_surfaces_io_errors_for_a_missing_file() {
+        let err = Config::load("/nonexistent/.git-tools.toml").unwrap_err();
+        assert!(matches!(err, ConfigError::Io(_)));
+    }
+
+    #[test]
+    fn wip_subjects_are_detected() {
+        for subject in ["WIP", "WIP fix the thing", "wip: fix the thing"] {
+            assert!(is_wip_subject(subject), "{subject:?} should be WIP");
+        }
+        for subject in ["fix: Handle empty input", "fixup! Add feature", "fixup!", "fixup! "] {
+            assert!(!is_wip_subject(subject), "{subject:?} should not be WIP");
+        }
+    }
+
+    #[test]
+    fn targetless_autosquash_subjects_are_detected() {
+        for subject in ["fixup!", "fixup! ", "squash!", "amend!"] {
+            assert!(
+                is_targetless_autosquash_subject(subject),
+                "{subject:?} should be a targetless autosquash marker"
+            );
+        }
+        for subject in ["fixup! Add feature", "fix: Handle empty input", "WIP"] {
+            assert!(
+                !is_targetless_autosquash_subject(subject),
+                "{subject:?} should not be a targetless autosquash marker"
+            );
+        }
+    }
+
+    #[test]
+    fn targetless_autosquash_subject_is_always_rejected_regardless_of_wip_policy() {
+        for policy in [WipPolicy::Accept, WipPolicy::Warn, WipPolicy::Reject] {
+            let config = Config {
+                wip_policy: policy,
+                ..Config::default()
+            };
+            let err = validate_commit_message("fixup! \n", Some(&config)).unwrap_err();
+            assert!(matches!(err, ValidationError::MessageSubjectInvalidFormat { .. }));
+
+            let report = validate_commit_message_report("fixup!\n", Some(&config));
+            assert!(!report.is_valid());
+            assert_eq!(report.violations[0].rule, "subject-invalid-format");
+        }
+    }
+
+    #[test]
+    fn wip_policy_defaults_to_accepting_autosquash_and_wip_subjects() {
+        validate_commit_message("fixup! Add feature\n", None).unwrap();
+        validate_commit_message("WIP\n", None).unwrap();
+    }
+
+    #[test]
+    fn wip_policy_reject_rejects_autosquash_and_wip_subjects() {
+        let config = Config {
+            wip_policy: WipPolicy::Reject,
+            ..Config::default()
+        };
+        let err = validate_commit_message("fixup! Add feature\n", Some(&config)).unwrap_err();
+        assert!(matches!(err, ValidationError::WorkInProgress { .. }));
+
+        let err = validate_commit_message("WIP\n", Some(&config)).unwrap_err();
+        assert!(matches!(err, ValidationError::WorkInProgress { .. }));
+    }
+
+    #[test]
+    fn wip_policy_warn_surfaces_a_non_blocking_violation() {
+        let config = Config {
+            wip_policy: WipPolicy::Warn,
+            ..Config::default()
+        };
+        let report = validate_commit_message_report("WIP\n", Some(&config));
+        assert!(report.is_valid());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(report.violations[0].rule, "work-in-progress");
+        assert_eq!(report.violations[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn version_increment_picks_the_highest_severity_bump() {
+        assert_eq!(
+            version_increment(&["fix: Handle empty input\n", "feat: Add a thing\n"], None),
+            VersionIncrement::Minor
+        );
+        assert_eq!(
+            version_increment(&["docs: Update README\n", "fix: Fix a crash\n"], None),
+            VersionIncrement::Patch
+        );
+        assert_eq!(
+            version_increment(&["perf: Reduce allocation overhead\n"], None),
+            VersionIncrement::Patch
+        );
+        assert_eq!(
+            version_increment(&["docs: Update README\n"], None),
+            VersionIncrement::None
+        );
+    }
+
+    #[test]
+    fn version_increment_is_major_for_a_bang_or_breaking_change_footer() {
+        assert_eq!(
+            version_increment(
+                &["feat!: Change API\n\nBREAKING CHANGE: Old thing removed\n"],
+                None
+            ),
+            VersionIncrement::Major
+        );
+        assert_eq!(
+            version_increment(
+                &[
+                    "fix: Tweak internals\n\nBREAKING CHANGE: Old thing removed\n",
+                    "feat: Add a thing\n",
+                ],
+                None
+            ),
+            VersionIncrement::Major
+        );
+    }
+
+    #[test]
+    fn version_increment_ignores_non_conventional_and_wip_messages() {
+        assert_eq!(
+            version_increment(&["WIP\n", "not a conventional commit\n"], None),
+            VersionIncrement::None
+        );
+    }
+
+    #[test]
+    fn version_increment_honors_a_custom_type_list() {
+        let config = Config {
+            types: Some(vec!["security".to_string()]),
+            ..Config::default()
+        };
+        assert_eq!(
+            version_increment(&["security: Patch a vulnerability\n"], None),
+            VersionIncrement::None,
+            "a custom type shouldn't bump anything without the matching config"
+        );
+        assert_eq!(
+            version_increment(&["security: Patch a vulnerability\n"], Some(&config)),
+            VersionIncrement::None,
+            "security isn't feat/fix/perf, so it still doesn't force a bump, but it must parse"
+        );
+        assert_eq!(
+            version_increment(
+                &["security!: Patch a vulnerability\n"],
+                Some(&config)
+            ),
+            VersionIncrement::Major,
+            "a bang on a custom type should still force Major once the type itself parses"
+        );
     }
 }